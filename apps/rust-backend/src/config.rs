@@ -0,0 +1,81 @@
+use std::env;
+use std::time::Duration;
+
+use actix_cors::Cors;
+use actix_web::http::header;
+
+/// Typed, environment-driven server configuration.
+///
+/// Everything the server needs to bind and behave safely in production is
+/// resolved once at startup so the rest of the code can depend on typed
+/// values instead of reading `env` ad hoc. Values are not validated here: the
+/// ports are kept as strings and an unparsable `SHUTDOWN_TIMEOUT_SECS` falls
+/// back to 30 seconds.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub host: String,
+    pub port: String,
+    pub grpc_port: String,
+    pub database_url: String,
+    pub cors_allowed_origins: Vec<String>,
+    pub shutdown_timeout: Duration,
+}
+
+impl Config {
+    /// Build the configuration from the process environment.
+    pub fn from_env() -> Self {
+        let host = env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
+        let port = env::var("PORT").unwrap_or_else(|_| "8080".to_string());
+        let grpc_port = env::var("GRPC_PORT").unwrap_or_else(|_| "50051".to_string());
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+
+        let cors_allowed_origins = env::var("CORS_ALLOWED_ORIGINS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .map(str::to_owned)
+            .collect();
+
+        let shutdown_timeout = env::var("SHUTDOWN_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| Duration::from_secs(30));
+
+        Self {
+            host,
+            port,
+            grpc_port,
+            database_url,
+            cors_allowed_origins,
+            shutdown_timeout,
+        }
+    }
+
+    pub fn http_bind_address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    pub fn grpc_bind_address(&self) -> String {
+        format!("{}:{}", self.host, self.grpc_port)
+    }
+
+    /// Build a restrictive CORS layer from the configured allow-list.
+    ///
+    /// Only the explicitly listed origins are permitted; an empty list means
+    /// no cross-origin browser client is allowed (same-origin requests are
+    /// unaffected). This replaces the previous allow-all policy.
+    pub fn cors(&self) -> Cors {
+        let mut cors = Cors::default()
+            .allowed_methods(vec!["GET", "POST", "PUT", "DELETE", "OPTIONS"])
+            .allowed_headers(vec![header::ACCEPT, header::CONTENT_TYPE, header::AUTHORIZATION])
+            .max_age(3600);
+
+        for origin in &self.cors_allowed_origins {
+            cors = cors.allowed_origin(origin);
+        }
+
+        cors
+    }
+}
@@ -0,0 +1,34 @@
+use tonic::{Request, Response, Status};
+
+/// Code generated from `proto/data_processor.proto` by `tonic-build`.
+pub mod pb {
+    tonic::include_proto!("dataprocessor");
+}
+
+use pb::data_processor_server::{DataProcessor, DataProcessorServer};
+use pb::{ProcessRequest, ProcessResponse};
+
+/// gRPC implementation of the data-processing RPC.
+#[derive(Default)]
+pub struct DataProcessorService;
+
+#[tonic::async_trait]
+impl DataProcessor for DataProcessorService {
+    async fn process(
+        &self,
+        request: Request<ProcessRequest>,
+    ) -> Result<Response<ProcessResponse>, Status> {
+        let payload = request.into_inner().payload;
+        log::debug!("gRPC process request: {} bytes", payload.len());
+
+        Ok(Response::new(ProcessResponse {
+            status: "processed".to_string(),
+            payload,
+        }))
+    }
+}
+
+/// Build the tonic service for mounting on a [`tonic::transport::Server`].
+pub fn service() -> DataProcessorServer<DataProcessorService> {
+    DataProcessorServer::new(DataProcessorService::default())
+}
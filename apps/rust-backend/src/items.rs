@@ -0,0 +1,106 @@
+use actix_web::{web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+/// A persisted resource as returned to API clients.
+#[derive(Debug, Serialize)]
+pub struct Item {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Payload for creating a new item.
+#[derive(Debug, Deserialize)]
+pub struct NewItem {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+// Create an item. Validates input and maps database failures to 5xx.
+async fn create_item(pool: web::Data<PgPool>, payload: web::Json<NewItem>) -> impl Responder {
+    let NewItem { name, description } = payload.into_inner();
+    if name.trim().is_empty() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "name must not be empty",
+        }));
+    }
+
+    let result = sqlx::query_as!(
+        Item,
+        r#"INSERT INTO items (name, description)
+           VALUES ($1, $2)
+           RETURNING id, name, description, created_at"#,
+        name,
+        description,
+    )
+    .fetch_one(pool.get_ref())
+    .await;
+
+    match result {
+        Ok(item) => HttpResponse::Created().json(item),
+        Err(err) => {
+            log::error!("failed to insert item: {}", err);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "failed to create item",
+            }))
+        }
+    }
+}
+
+// List all items, newest first.
+async fn list_items(pool: web::Data<PgPool>) -> impl Responder {
+    let result = sqlx::query_as!(
+        Item,
+        r#"SELECT id, name, description, created_at
+           FROM items
+           ORDER BY created_at DESC"#,
+    )
+    .fetch_all(pool.get_ref())
+    .await;
+
+    match result {
+        Ok(items) => HttpResponse::Ok().json(items),
+        Err(err) => {
+            log::error!("failed to list items: {}", err);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "failed to list items",
+            }))
+        }
+    }
+}
+
+// Fetch a single item by id, 404 when absent.
+async fn get_item(pool: web::Data<PgPool>, id: web::Path<i64>) -> impl Responder {
+    let result = sqlx::query_as!(
+        Item,
+        r#"SELECT id, name, description, created_at
+           FROM items
+           WHERE id = $1"#,
+        id.into_inner(),
+    )
+    .fetch_optional(pool.get_ref())
+    .await;
+
+    match result {
+        Ok(Some(item)) => HttpResponse::Ok().json(item),
+        Ok(None) => HttpResponse::NotFound().json(serde_json::json!({
+            "error": "item not found",
+        })),
+        Err(err) => {
+            log::error!("failed to fetch item: {}", err);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "failed to fetch item",
+            }))
+        }
+    }
+}
+
+/// Register the item routes under `/api/items`.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/api/items", web::post().to(create_item))
+        .route("/api/items", web::get().to(list_items))
+        .route("/api/items/{id}", web::get().to(get_item));
+}
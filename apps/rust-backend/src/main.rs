@@ -1,8 +1,33 @@
 use actix_web::{web, App, HttpResponse, HttpServer, Responder};
-use actix_cors::Cors;
 use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgPoolOptions;
 use sqlx::PgPool;
-use std::env;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+mod config;
+mod grpc;
+mod items;
+mod metrics;
+mod single_flight;
+
+use config::Config;
+
+use single_flight::SingleFlight;
+
+/// Upper bound on distinct in-flight computations tracked by the coalescer.
+const COALESCE_CAPACITY: usize = 1024;
+
+/// Shared result of a data-processing computation. Cheap to clone so multiple
+/// coalesced waiters can each take a copy.
+type ProcessResult = Arc<serde_json::Value>;
+
+/// How long an individual dependency probe is allowed to take before the
+/// readiness check reports it as unhealthy.
+const READINESS_TIMEOUT: Duration = Duration::from_secs(2);
 
 #[derive(Debug, Serialize, Deserialize)]
 struct HealthResponse {
@@ -11,15 +36,15 @@ struct HealthResponse {
     version: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct MetricsResponse {
-    cpu_usage: f64,
-    memory_usage: f64,
-    request_count: u64,
+#[derive(Debug, Serialize)]
+struct ReadinessResponse {
+    status: &'static str,
+    checks: BTreeMap<&'static str, &'static str>,
 }
 
-// Health check endpoint
-async fn health() -> impl Responder {
+// Liveness probe: process is up. Kept cheap so kubelet never restarts a pod
+// that is merely waiting on a downstream dependency.
+async fn health_live() -> impl Responder {
     HttpResponse::Ok().json(HealthResponse {
         status: "healthy".to_string(),
         service: "rust-backend".to_string(),
@@ -27,37 +52,112 @@ async fn health() -> impl Responder {
     })
 }
 
-// Performance-critical endpoint example
-async fn process_data(data: web::Json<serde_json::Value>) -> impl Responder {
-    // High-performance data processing here
-    // This is where Rust shines for CPU-intensive tasks
-    
-    log::info!("Processing data: {:?}", data);
-    
-    HttpResponse::Ok().json(serde_json::json!({
+// Readiness probe: the service can actually serve traffic. Every configured
+// dependency is exercised with a short timeout; a single failure flips the
+// whole response to 503 so the pod is pulled out of rotation.
+async fn health_ready(pool: web::Data<PgPool>) -> impl Responder {
+    let mut checks = BTreeMap::new();
+
+    let database = match tokio::time::timeout(
+        READINESS_TIMEOUT,
+        sqlx::query("SELECT 1").execute(pool.get_ref()),
+    )
+    .await
+    {
+        Ok(Ok(_)) => "ok",
+        Ok(Err(err)) => {
+            log::warn!("readiness: database query failed: {}", err);
+            "error"
+        }
+        Err(_) => {
+            log::warn!("readiness: database query timed out");
+            "timeout"
+        }
+    };
+    checks.insert("database", database);
+
+    let healthy = checks.values().all(|status| *status == "ok");
+    let body = ReadinessResponse {
+        status: if healthy { "ready" } else { "not_ready" },
+        checks,
+    };
+
+    if healthy {
+        HttpResponse::Ok().json(body)
+    } else {
+        HttpResponse::ServiceUnavailable().json(body)
+    }
+}
+
+// The actual CPU-intensive work. Kept separate so the coalescer can own it as
+// a boxed future and run it once for each group of concurrent identical request
+// bodies; an identical request arriving after that run has finished runs it again.
+fn compute(value: serde_json::Value) -> ProcessResult {
+    Arc::new(serde_json::json!({
         "status": "processed",
-        "data": data.into_inner()
+        "data": value,
     }))
 }
 
-// Metrics endpoint for Prometheus
-async fn metrics() -> impl Responder {
-    // In production, use prometheus crate for real metrics
-    HttpResponse::Ok().json(MetricsResponse {
-        cpu_usage: 45.2,
-        memory_usage: 512.0,
-        request_count: 12345,
-    })
+// Performance-critical endpoint example.
+//
+// Identical concurrent requests are collapsed to a single execution via the
+// [`SingleFlight`] coalescer, keyed by a hash of the raw request body.
+async fn process_data(
+    body: web::Bytes,
+    coalescer: web::Data<SingleFlight<ProcessResult>>,
+) -> impl Responder {
+    let value: serde_json::Value = match serde_json::from_slice(&body) {
+        Ok(value) => value,
+        Err(err) => {
+            return HttpResponse::BadRequest().json(serde_json::json!({
+                "status": "error",
+                "error": err.to_string(),
+            }))
+        }
+    };
+
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    let key = hasher.finish();
+
+    log::info!("Processing data (key={:x}): {:?}", key, value);
+
+    let result = coalescer
+        .run(key, move || {
+            Box::pin(async move { compute(value) })
+        })
+        .await;
+
+    HttpResponse::Ok().json(result.as_ref())
 }
 
-// gRPC-like high-performance API endpoint
-async fn grpc_handler(payload: web::Bytes) -> impl Responder {
-    log::debug!("Received gRPC-style request: {} bytes", payload.len());
-    
-    // Process binary data with zero-copy when possible
-    HttpResponse::Ok()
-        .content_type("application/octet-stream")
-        .body(payload)
+// Resolve once either SIGTERM (Kubernetes rolling deploys) or Ctrl-C arrives,
+// so long-lived servers can begin a graceful drain.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("install Ctrl-C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+
+    log::info!("shutdown signal received, draining in-flight requests");
 }
 
 #[actix_web::main]
@@ -68,33 +168,75 @@ async fn main() -> std::io::Result<()> {
     // Load environment variables
     dotenv::dotenv().ok();
     
-    let host = env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
-    let port = env::var("PORT").unwrap_or_else(|_| "8080".to_string());
-    let bind_address = format!("{}:{}", host, port);
-    
-    log::info!("Starting Rust backend server on {}", bind_address);
-    
-    // Optional: Database connection pool
-    // let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    // let pool = PgPool::connect(&database_url).await.expect("Failed to connect to database");
-    
-    HttpServer::new(move || {
-        // Configure CORS
-        let cors = Cors::default()
-            .allow_any_origin()
-            .allow_any_method()
-            .allow_any_header()
-            .max_age(3600);
-        
+    let config = Config::from_env();
+    let bind_address = config.http_bind_address();
+    let grpc_address = config.grpc_bind_address();
+
+    log::info!(
+        "Starting Rust backend: HTTP on {}, gRPC on {}",
+        bind_address,
+        grpc_address
+    );
+
+    // Database connection pool, shared across workers via web::Data.
+    let pool = PgPoolOptions::new()
+        .max_connections(5)
+        .acquire_timeout(READINESS_TIMEOUT)
+        .connect(&config.database_url)
+        .await
+        .expect("Failed to connect to database");
+
+    sqlx::migrate!()
+        .run(&pool)
+        .await
+        .expect("Failed to run database migrations");
+
+    let pool = web::Data::new(pool);
+
+    let metrics = web::Data::new(metrics::Metrics::new());
+    let coalescer: web::Data<SingleFlight<ProcessResult>> =
+        web::Data::new(SingleFlight::new(COALESCE_CAPACITY));
+
+    let cors_config = config.clone();
+    let http_server = HttpServer::new(move || {
         App::new()
-            .wrap(cors)
+            .app_data(metrics.clone())
+            .app_data(pool.clone())
+            .app_data(coalescer.clone())
+            .wrap(cors_config.cors())
             .wrap(actix_web::middleware::Logger::default())
-            .route("/health", web::get().to(health))
-            .route("/metrics", web::get().to(metrics))
+            .wrap(metrics::RequestMetrics)
+            .route("/health", web::get().to(health_live))
+            .route("/health/live", web::get().to(health_live))
+            .route("/health/ready", web::get().to(health_ready))
+            .route("/metrics", web::get().to(metrics::metrics))
             .route("/api/process", web::post().to(process_data))
-            .route("/api/grpc", web::post().to(grpc_handler))
+            .configure(items::configure)
     })
     .bind(&bind_address)?
-    .run()
-    .await
+    // On SIGTERM actix stops accepting new connections and drains in-flight
+    // requests for up to this many seconds before forcing the sockets closed
+    // (SIGINT and SIGQUIT make actix stop without draining).
+    .shutdown_timeout(config.shutdown_timeout.as_secs())
+    .run();
+
+    let grpc_socket = grpc_address
+        .parse()
+        .expect("GRPC_PORT/HOST form a valid socket address");
+    let grpc_server = tonic::transport::Server::builder()
+        .add_service(grpc::service())
+        .serve_with_shutdown(grpc_socket, shutdown_signal());
+
+    // Drive the HTTP and gRPC servers on the same runtime. actix drains its own
+    // connections on signal via `shutdown_timeout`; tonic is told to stop via
+    // the shared shutdown future. If either server fails, `try_join!` returns
+    // its error at once and the process exits so the orchestrator can
+    // reschedule the pod; a server that stops cleanly waits for the other.
+    tokio::try_join!(http_server, async {
+        grpc_server
+            .await
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))
+    })?;
+
+    Ok(())
 }
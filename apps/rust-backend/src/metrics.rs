@@ -0,0 +1,148 @@
+use std::future::{ready, Ready};
+use std::rc::Rc;
+use std::time::Instant;
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{web, Error, HttpResponse, Responder};
+use futures_util::future::LocalBoxFuture;
+use prometheus::{Encoder, HistogramVec, IntCounterVec, Registry, TextEncoder};
+
+/// Prometheus metrics shared across the application.
+///
+/// A single [`Registry`] owns every collector so that `/metrics` can render the
+/// whole set in one pass. The struct is cheap to clone (everything behind it is
+/// reference counted inside the prometheus crate) and is injected through
+/// [`web::Data`].
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Registry,
+    requests_total: IntCounterVec,
+    request_duration_seconds: HistogramVec,
+}
+
+impl Metrics {
+    /// Build a fresh registry and register the default request collectors.
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let requests_total = IntCounterVec::new(
+            prometheus::opts!("http_requests_total", "Total number of HTTP requests"),
+            &["method", "path", "status"],
+        )
+        .expect("valid requests_total metric");
+
+        let request_duration_seconds = HistogramVec::new(
+            prometheus::histogram_opts!(
+                "http_request_duration_seconds",
+                "HTTP request latency in seconds"
+            ),
+            &["method", "path", "status"],
+        )
+        .expect("valid request_duration_seconds metric");
+
+        registry
+            .register(Box::new(requests_total.clone()))
+            .expect("register requests_total");
+        registry
+            .register(Box::new(request_duration_seconds.clone()))
+            .expect("register request_duration_seconds");
+
+        Self {
+            registry,
+            requests_total,
+            request_duration_seconds,
+        }
+    }
+
+    /// Record a completed request keyed by method, matched path and status.
+    fn observe(&self, method: &str, path: &str, status: u16, elapsed: f64) {
+        let status = status.to_string();
+        let labels = [method, path, status.as_str()];
+        self.requests_total.with_label_values(&labels).inc();
+        self.request_duration_seconds
+            .with_label_values(&labels)
+            .observe(elapsed);
+    }
+
+    /// Render the registry in the Prometheus text exposition format.
+    fn encode(&self) -> String {
+        let encoder = TextEncoder::new();
+        let mut buf = Vec::new();
+        encoder
+            .encode(&self.registry.gather(), &mut buf)
+            .expect("encode metrics");
+        String::from_utf8(buf).expect("metrics are valid utf-8")
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `/metrics` handler emitting the Prometheus text exposition format.
+pub async fn metrics(metrics: web::Data<Metrics>) -> impl Responder {
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(metrics.encode())
+}
+
+/// Middleware factory that records a counter and latency histogram per request.
+pub struct RequestMetrics;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestMetrics
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestMetricsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestMetricsMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct RequestMetricsMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestMetricsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        // Prefer the matched route pattern so label cardinality stays bounded;
+        // fall back to the raw path for unmatched requests.
+        let method = req.method().as_str().to_owned();
+        let path = req
+            .match_pattern()
+            .unwrap_or_else(|| req.path().to_owned());
+        let metrics = req.app_data::<web::Data<Metrics>>().map(|m| m.clone());
+
+        Box::pin(async move {
+            let started = Instant::now();
+            let res = service.call(req).await?;
+            if let Some(metrics) = metrics {
+                let elapsed = started.elapsed().as_secs_f64();
+                metrics.observe(&method, &path, res.status().as_u16(), elapsed);
+            }
+            Ok(res)
+        })
+    }
+}
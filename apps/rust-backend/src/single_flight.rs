@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+
+use futures_util::future::{BoxFuture, FutureExt, Shared};
+
+type SharedFut<V> = Shared<BoxFuture<'static, V>>;
+
+/// Deduplicates concurrent identical computations so a thundering herd of
+/// duplicate requests only runs the work once.
+///
+/// Each in-flight computation is held as a clonable [`Shared`] future. Waiters
+/// join by awaiting a clone of the same future; the map only holds a [`Weak`]
+/// reference, so an entry can no longer be joined once every waiter has
+/// dropped its handle. Such dead entries are not removed when the computation
+/// finishes: they stay in the map until the same key is registered again or
+/// until a sweep when the map reaches `capacity`. The map size is hard-capped
+/// at `capacity`: once
+/// that many distinct keys are concurrently in-flight, further distinct
+/// requests run uncoalesced rather than growing the map without bound.
+pub struct SingleFlight<V: Clone + Send + 'static> {
+    inflight: Mutex<HashMap<u64, Weak<SharedFut<V>>>>,
+    capacity: usize,
+}
+
+impl<V: Clone + Send + 'static> SingleFlight<V> {
+    /// Create an empty coalescer that tracks at most `capacity` live entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inflight: Mutex::new(HashMap::new()),
+            capacity,
+        }
+    }
+
+    /// Run `make` for `key`, or join an already in-flight computation for the
+    /// same key and return its result.
+    ///
+    /// The lock is only held while (de)registering the shared future — never
+    /// across the `await` — so computations run concurrently across keys.
+    pub async fn run<F>(&self, key: u64, make: F) -> V
+    where
+        F: FnOnce() -> BoxFuture<'static, V>,
+    {
+        let shared = {
+            let mut map = self.inflight.lock().expect("single-flight lock");
+            if let Some(existing) = map.get(&key).and_then(Weak::upgrade) {
+                existing
+            } else {
+                let shared = Arc::new(make().shared());
+                if map.len() >= self.capacity {
+                    // First reclaim entries whose waiters have all gone.
+                    map.retain(|_, weak| weak.strong_count() > 0);
+                }
+                // Only register while there is room, so the live map size can
+                // never exceed `capacity`. When full, the computation still
+                // runs — it just isn't offered for coalescing.
+                if map.len() < self.capacity {
+                    map.insert(key, Arc::downgrade(&shared));
+                }
+                shared
+            }
+        };
+
+        (*shared).clone().await
+    }
+}
@@ -0,0 +1,163 @@
+//! Integration tests for the item resource API.
+//!
+//! Each test provisions an isolated, uniquely-named database, runs the
+//! migrations against it and drives the endpoints through an in-process actix
+//! app with `test::call_service`. The throwaway database is dropped on
+//! teardown so tests never share state.
+
+use actix_web::{test, web, App};
+use sqlx::{Connection, Executor, PgConnection, PgPool};
+use uuid::Uuid;
+
+use std::thread;
+
+// Module under test. The binary crate exposes its handlers via `items`.
+#[path = "../src/items.rs"]
+mod items;
+
+/// A database created for the lifetime of a single test.
+struct TestDb {
+    admin_url: String,
+    name: String,
+    pool: PgPool,
+}
+
+impl TestDb {
+    async fn spawn() -> Self {
+        let base_url =
+            std::env::var("DATABASE_URL").expect("DATABASE_URL must be set to run the tests");
+        let name = format!("test_{}", Uuid::new_v4().simple());
+
+        // Connect to the maintenance database to create the fresh one.
+        let (admin_url, db_url) = split_database_url(&base_url, &name);
+        let mut admin = PgConnection::connect(&admin_url)
+            .await
+            .expect("connect to admin database");
+        admin
+            .execute(format!(r#"CREATE DATABASE "{}""#, name).as_str())
+            .await
+            .expect("create test database");
+
+        let pool = PgPool::connect(&db_url)
+            .await
+            .expect("connect to test database");
+        sqlx::migrate!()
+            .run(&pool)
+            .await
+            .expect("run migrations");
+
+        Self {
+            admin_url,
+            name,
+            pool,
+        }
+    }
+}
+
+/// Reclaim the throwaway database on drop so a failed assertion (which panics
+/// before the end of the test) can't leak an orphan database on the server.
+///
+/// `Drop` is synchronous, so the async cleanup runs on a short-lived thread
+/// with its own runtime that we join before returning.
+impl Drop for TestDb {
+    fn drop(&mut self) {
+        let admin_url = self.admin_url.clone();
+        let name = self.name.clone();
+        let pool = self.pool.clone();
+
+        let cleanup = thread::spawn(move || {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("build cleanup runtime");
+            rt.block_on(async move {
+                pool.close().await;
+                let mut admin = PgConnection::connect(&admin_url)
+                    .await
+                    .expect("connect to admin database");
+                admin
+                    .execute(
+                        format!(r#"DROP DATABASE IF EXISTS "{}" WITH (FORCE)"#, name).as_str(),
+                    )
+                    .await
+                    .expect("drop test database");
+            });
+        });
+
+        // Don't mask the test's own failure: if cleanup itself panicked while
+        // the test was already unwinding, just log it.
+        if cleanup.join().is_err() && !thread::panicking() {
+            panic!("test database cleanup failed");
+        }
+    }
+}
+
+/// Swap the database segment of a connection URL for `name`, returning the
+/// admin URL (pointing at `postgres`) and the test URL.
+fn split_database_url(base_url: &str, name: &str) -> (String, String) {
+    let (prefix, _db) = base_url
+        .rsplit_once('/')
+        .expect("DATABASE_URL has a database segment");
+    (format!("{}/postgres", prefix), format!("{}/{}", prefix, name))
+}
+
+#[actix_web::test]
+async fn create_then_fetch_item() {
+    let db = TestDb::spawn().await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .configure(items::configure),
+    )
+    .await;
+
+    let create = test::TestRequest::post()
+        .uri("/api/items")
+        .set_json(serde_json::json!({ "name": "widget", "description": "a thing" }))
+        .to_request();
+    let created: serde_json::Value = test::call_and_read_body_json(&app, create).await;
+    assert_eq!(created["name"], "widget");
+    let id = created["id"].as_i64().expect("item id");
+
+    let get = test::TestRequest::get()
+        .uri(&format!("/api/items/{}", id))
+        .to_request();
+    let fetched: serde_json::Value = test::call_and_read_body_json(&app, get).await;
+    assert_eq!(fetched["id"], id);
+    assert_eq!(fetched["description"], "a thing");
+}
+
+#[actix_web::test]
+async fn rejects_empty_name() {
+    let db = TestDb::spawn().await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .configure(items::configure),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/api/items")
+        .set_json(serde_json::json!({ "name": "   " }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn missing_item_is_404() {
+    let db = TestDb::spawn().await;
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .configure(items::configure),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/items/999999")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+}